@@ -0,0 +1,5 @@
+//! Authentication primitives shared by the User-based Security Model.
+
+/// Maximum allowed difference, in seconds, between the local and the message's `engine_time`
+/// values before a message is declared outside of the time window (RFC 3414 §3.2).
+pub const TIME_WINDOW: i32 = 150;
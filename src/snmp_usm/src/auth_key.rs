@@ -0,0 +1,164 @@
+//! Authentication digests and localized keys used by the User-based Security Model.
+
+use hmac::{Hmac, Mac};
+use md5::Md5 as Md5Hash;
+use sha1::Sha1 as Sha1Hash;
+use sha2::{Sha224 as Sha224Hash, Sha256 as Sha256Hash, Sha384 as Sha384Hash, Sha512 as Sha512Hash};
+
+/// Length, in bytes, that a password is expanded to before it is hashed, per RFC 3414 Appendix
+/// A.2.
+const PASSWORD_EXPANSION_LEN: usize = 1_048_576;
+
+/// An authentication digest usable with the User-based Security Model.
+///
+/// Implementors provide the hash used to localize a password into a [LocalizedKey] as well as the
+/// HMAC used to authenticate messages, together with the truncation length mandated for their
+/// `auth_params`.
+pub trait Digest {
+    /// Length, in bytes, of the full digest output.
+    const OUTPUT_LEN: usize;
+
+    /// Length, in bytes, that the HMAC output is truncated to when carried in `auth_params`.
+    const TRUNCATED_LEN: usize;
+
+    /// Hashes `data`, returning the full, untruncated digest.
+    fn hash(data: &[u8]) -> Vec<u8>;
+
+    /// Computes the HMAC of `data` under `key`, truncated to [TRUNCATED_LEN](#associatedconstant.TRUNCATED_LEN)
+    /// bytes.
+    fn hmac(key: &[u8], data: &[u8]) -> Vec<u8>;
+}
+
+macro_rules! impl_digest {
+    ($name:ident, $hash_ty:ty, $output_len:expr, $truncated_len:expr) => {
+        #[doc = concat!("The ", stringify!($name), " authentication digest.")]
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        pub struct $name;
+
+        impl Digest for $name {
+            const OUTPUT_LEN: usize = $output_len;
+            const TRUNCATED_LEN: usize = $truncated_len;
+
+            fn hash(data: &[u8]) -> Vec<u8> {
+                <$hash_ty as digest::Digest>::digest(data).to_vec()
+            }
+
+            fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+                let mut mac = Hmac::<$hash_ty>::new_from_slice(key)
+                    .expect("HMAC can be initialized with a key of any length");
+                mac.update(data);
+
+                mac.finalize().into_bytes()[..Self::TRUNCATED_LEN].to_vec()
+            }
+        }
+    };
+}
+
+impl_digest!(Md5, Md5Hash, 16, 12);
+impl_digest!(Sha1, Sha1Hash, 20, 12);
+// Truncation lengths per RFC 7860: usmHMAC128SHA224 = 128 bits, usmHMAC192SHA256 = 192 bits,
+// usmHMAC256SHA384 = 256 bits, usmHMAC384SHA512 = 384 bits.
+impl_digest!(Sha224, Sha224Hash, 28, 16);
+impl_digest!(Sha256, Sha256Hash, 32, 24);
+impl_digest!(Sha384, Sha384Hash, 48, 32);
+impl_digest!(Sha512, Sha512Hash, 64, 48);
+
+/// A password localized to a specific SNMP engine, per RFC 3414 Appendix A.2.
+///
+/// # Examples
+///
+/// ```
+/// use snmp_usm::auth_key::{Digest, LocalizedKey, Sha256};
+///
+/// let localized_key = LocalizedKey::localize::<Sha256>(b"a password", b"an engine id");
+/// let auth_params = localized_key.sign::<Sha256>(b"the whole message");
+///
+/// assert_eq!(auth_params.len(), Sha256::TRUNCATED_LEN);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LocalizedKey {
+    key: Vec<u8>,
+}
+
+impl LocalizedKey {
+    /// Localizes `password` for the engine identified by `engine_id` using digest `D`.
+    ///
+    /// This computes `Kul = H(H(password-expanded) || engineID || H(password-expanded))`, where
+    /// `password-expanded` repeats `password` until it is 1 MB long.
+    pub fn localize<D: Digest>(password: &[u8], engine_id: &[u8]) -> Self {
+        let ku = D::hash(&expand_password(password));
+
+        let mut buf = Vec::with_capacity(ku.len() * 2 + engine_id.len());
+        buf.extend_from_slice(&ku);
+        buf.extend_from_slice(engine_id);
+        buf.extend_from_slice(&ku);
+
+        Self { key: D::hash(&buf) }
+    }
+
+    /// Returns the raw localized key bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.key
+    }
+
+    /// Computes the authentication parameters for `data` (the whole message, with `auth_params`
+    /// set to its placeholder value) using digest `D`.
+    pub fn sign<D: Digest>(&self, data: &[u8]) -> Vec<u8> {
+        D::hmac(&self.key, data)
+    }
+}
+
+fn expand_password(password: &[u8]) -> Vec<u8> {
+    if password.is_empty() {
+        return vec![0; PASSWORD_EXPANSION_LEN];
+    }
+
+    password
+        .iter()
+        .copied()
+        .cycle()
+        .take(PASSWORD_EXPANSION_LEN)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_localizes_the_same_password_deterministically() {
+        let first = LocalizedKey::localize::<Sha256>(b"password", b"engine");
+        let second = LocalizedKey::localize::<Sha256>(b"password", b"engine");
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn it_localizes_differently_per_engine() {
+        let first = LocalizedKey::localize::<Sha256>(b"password", b"engine-a");
+        let second = LocalizedKey::localize::<Sha256>(b"password", b"engine-b");
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn it_truncates_auth_params_per_digest() {
+        let key = LocalizedKey::localize::<Md5>(b"password", b"engine");
+        assert_eq!(key.sign::<Md5>(b"message").len(), 12);
+
+        let key = LocalizedKey::localize::<Sha1>(b"password", b"engine");
+        assert_eq!(key.sign::<Sha1>(b"message").len(), 12);
+
+        let key = LocalizedKey::localize::<Sha224>(b"password", b"engine");
+        assert_eq!(key.sign::<Sha224>(b"message").len(), 16);
+
+        let key = LocalizedKey::localize::<Sha256>(b"password", b"engine");
+        assert_eq!(key.sign::<Sha256>(b"message").len(), 24);
+
+        let key = LocalizedKey::localize::<Sha384>(b"password", b"engine");
+        assert_eq!(key.sign::<Sha384>(b"message").len(), 32);
+
+        let key = LocalizedKey::localize::<Sha512>(b"password", b"engine");
+        assert_eq!(key.sign::<Sha512>(b"message").len(), 48);
+    }
+}
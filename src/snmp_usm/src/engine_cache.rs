@@ -0,0 +1,155 @@
+//! A Local Configuration Datastore (LCD) caching discovered authoritative engines.
+
+use crate::SecurityParams;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    engine_id: Vec<u8>,
+    engine_boots: i32,
+    engine_time: i32,
+    updated_at: Instant,
+}
+
+/// Caches each authoritative engine's discovered `engine_id`, `engine_boots` and `engine_time`,
+/// keyed by transport peer `P` (e.g. a socket address), so a manager does not have to re-run
+/// discovery on every message, per RFC 3414 §4.
+///
+/// # Examples
+///
+/// ```
+/// use snmp_usm::engine_cache::EngineCache;
+/// use snmp_usm::SecurityParams;
+///
+/// let mut cache: EngineCache<&str> = EngineCache::new();
+///
+/// // Discovery: send `cache.seed_discovery("peer")`'s security params, then learn the engine's
+/// // identity from the resulting Report PDU.
+/// let mut report_params = SecurityParams::new();
+/// report_params.set_engine_id(b"engine").set_engine_boots(1).set_engine_time(100);
+/// cache.learn_from_report("peer", &report_params);
+///
+/// // Later outgoing messages stamp in fresh timeliness values without re-discovering.
+/// let security_params = cache.stamp(&"peer").unwrap();
+/// assert_eq!(security_params.engine_id(), b"engine");
+/// ```
+pub struct EngineCache<P: Eq + Hash> {
+    entries: HashMap<P, CacheEntry>,
+}
+
+impl<P: Eq + Hash> EngineCache<P> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Builds the security parameters for a discovery request to `peer`.
+    ///
+    /// If `peer`'s authoritative engine was already discovered, its cached `engine_id` is
+    /// included so a report generator can skip straight to validating timeliness; otherwise
+    /// empty discovery parameters, per [SecurityParams::discovery](
+    /// ../struct.SecurityParams.html#method.discovery), are returned.
+    pub fn seed_discovery(&self, peer: &P) -> SecurityParams {
+        let mut security_params = SecurityParams::discovery();
+
+        if let Some(entry) = self.entries.get(peer) {
+            security_params.set_engine_id(&entry.engine_id);
+        }
+
+        security_params
+    }
+
+    /// Learns `peer`'s authoritative engine ID, boots and time from a Report response.
+    pub fn learn_from_report(&mut self, peer: P, security_params: &SecurityParams) {
+        self.entries.insert(
+            peer,
+            CacheEntry {
+                engine_id: security_params.engine_id().to_vec(),
+                engine_boots: security_params.engine_boots(),
+                engine_time: security_params.engine_time(),
+                updated_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Builds fresh security parameters for an outgoing message to `peer`, advancing the cached
+    /// `engine_time` by the wall-clock time elapsed since the last update.
+    ///
+    /// Returns `None` if `peer`'s authoritative engine has not been discovered yet.
+    pub fn stamp(&self, peer: &P) -> Option<SecurityParams> {
+        let entry = self.entries.get(peer)?;
+        let elapsed_secs = entry.updated_at.elapsed().as_secs() as i32;
+
+        let mut security_params = SecurityParams::new();
+        security_params
+            .set_engine_id(&entry.engine_id)
+            .set_engine_boots(entry.engine_boots)
+            .set_engine_time(entry.engine_time.saturating_add(elapsed_secs));
+
+        Some(security_params)
+    }
+}
+
+impl<P: Eq + Hash> Default for EngineCache<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_has_no_cached_engine_before_discovery() {
+        let cache: EngineCache<&str> = EngineCache::new();
+
+        assert_eq!(cache.seed_discovery(&"peer").engine_id(), b"");
+        assert_eq!(cache.stamp(&"peer"), None);
+    }
+
+    #[test]
+    fn it_seeds_discovery_with_the_cached_engine_id_once_learned() {
+        let mut cache: EngineCache<&str> = EngineCache::new();
+        let mut report_params = SecurityParams::new();
+        report_params.set_engine_id(b"engine");
+        cache.learn_from_report("peer", &report_params);
+
+        assert_eq!(cache.seed_discovery(&"peer").engine_id(), b"engine");
+    }
+
+    #[test]
+    fn it_stamps_fresh_security_params_from_a_learned_engine() {
+        let mut cache: EngineCache<&str> = EngineCache::new();
+        let mut report_params = SecurityParams::new();
+        report_params
+            .set_engine_id(b"engine")
+            .set_engine_boots(3)
+            .set_engine_time(1_000);
+        cache.learn_from_report("peer", &report_params);
+
+        let security_params = cache.stamp(&"peer").unwrap();
+        assert_eq!(security_params.engine_id(), b"engine");
+        assert_eq!(security_params.engine_boots(), 3);
+        assert!(security_params.engine_time() >= 1_000);
+    }
+
+    #[test]
+    fn it_keeps_separate_entries_per_peer() {
+        let mut cache: EngineCache<&str> = EngineCache::new();
+        let mut params_a = SecurityParams::new();
+        params_a.set_engine_id(b"engine-a");
+        let mut params_b = SecurityParams::new();
+        params_b.set_engine_id(b"engine-b");
+
+        cache.learn_from_report("peer-a", &params_a);
+        cache.learn_from_report("peer-b", &params_b);
+
+        assert_eq!(cache.stamp(&"peer-a").unwrap().engine_id(), b"engine-a");
+        assert_eq!(cache.stamp(&"peer-b").unwrap().engine_id(), b"engine-b");
+    }
+}
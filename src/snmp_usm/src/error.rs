@@ -0,0 +1,126 @@
+use std::error;
+use std::fmt;
+
+/// A specialized [`Result`](std::result::Result) type for operations in this crate.
+pub type SecurityResult<T> = Result<T, SecurityError>;
+
+/// `usmStatsUnsupportedSecLevels`.
+const UNSUPPORTED_SECURITY_LEVEL_OID: [u32; 10] = [1, 3, 6, 1, 6, 3, 15, 1, 1, 1];
+/// `usmStatsNotInTimeWindows`.
+const NOT_IN_TIME_WINDOW_OID: [u32; 10] = [1, 3, 6, 1, 6, 3, 15, 1, 1, 2];
+/// `usmStatsUnknownUserNames`.
+const UNKNOWN_USER_NAME_OID: [u32; 10] = [1, 3, 6, 1, 6, 3, 15, 1, 1, 3];
+/// `usmStatsUnknownEngineIDs`.
+const UNKNOWN_ENGINE_ID_OID: [u32; 10] = [1, 3, 6, 1, 6, 3, 15, 1, 1, 4];
+/// `usmStatsWrongDigests`.
+const WRONG_DIGEST_OID: [u32; 10] = [1, 3, 6, 1, 6, 3, 15, 1, 1, 5];
+/// `usmStatsDecryptionErrors`.
+const DECRYPTION_ERROR_OID: [u32; 10] = [1, 3, 6, 1, 6, 3, 15, 1, 1, 6];
+
+/// Errors that can occur while processing User-based Security Model parameters.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum SecurityError {
+    /// The security parameters could not be decoded.
+    MalformedSecurityParams,
+    /// The message is outside of the time window allowed by RFC 3414 §3.2.
+    NotInTimeWindow,
+    /// The requested security level is not supported for this user.
+    UnsupportedSecurityLevel,
+    /// The username in the message is not known to this engine.
+    UnknownUserName,
+    /// The authoritative engine ID in the message is not known to this engine.
+    UnknownEngineId,
+    /// The computed authentication parameters did not match the ones carried in the message.
+    WrongDigest,
+    /// The encrypted scoped PDU could not be decrypted.
+    DecryptionError,
+}
+
+impl SecurityError {
+    /// Returns the well-known OID of the `usmStats*` counter an authoritative engine's report
+    /// generator attaches to a Report PDU sent in response to this error, per RFC 3414 §5.
+    ///
+    /// Returns `None` for errors, like [MalformedSecurityParams](#variant.MalformedSecurityParams),
+    /// that do not correspond to one of the `usmStats*` counters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use snmp_usm::SecurityError;
+    ///
+    /// assert_eq!(
+    ///     SecurityError::NotInTimeWindow.report_oid(),
+    ///     Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 2][..])
+    /// );
+    /// assert_eq!(SecurityError::MalformedSecurityParams.report_oid(), None);
+    /// ```
+    pub fn report_oid(&self) -> Option<&'static [u32]> {
+        match self {
+            SecurityError::MalformedSecurityParams => None,
+            SecurityError::UnsupportedSecurityLevel => Some(&UNSUPPORTED_SECURITY_LEVEL_OID),
+            SecurityError::NotInTimeWindow => Some(&NOT_IN_TIME_WINDOW_OID),
+            SecurityError::UnknownUserName => Some(&UNKNOWN_USER_NAME_OID),
+            SecurityError::UnknownEngineId => Some(&UNKNOWN_ENGINE_ID_OID),
+            SecurityError::WrongDigest => Some(&WRONG_DIGEST_OID),
+            SecurityError::DecryptionError => Some(&DECRYPTION_ERROR_OID),
+        }
+    }
+}
+
+impl fmt::Display for SecurityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecurityError::MalformedSecurityParams => {
+                write!(f, "malformed security parameters")
+            }
+            SecurityError::NotInTimeWindow => write!(f, "message is not in the time window"),
+            SecurityError::UnsupportedSecurityLevel => {
+                write!(f, "unsupported security level")
+            }
+            SecurityError::UnknownUserName => write!(f, "unknown user name"),
+            SecurityError::UnknownEngineId => write!(f, "unknown engine id"),
+            SecurityError::WrongDigest => write!(f, "wrong digest"),
+            SecurityError::DecryptionError => write!(f, "decryption error"),
+        }
+    }
+}
+
+impl error::Error for SecurityError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_report_oid_for_each_usm_stats_error() {
+        assert_eq!(
+            SecurityError::UnsupportedSecurityLevel.report_oid(),
+            Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 1][..])
+        );
+        assert_eq!(
+            SecurityError::NotInTimeWindow.report_oid(),
+            Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 2][..])
+        );
+        assert_eq!(
+            SecurityError::UnknownUserName.report_oid(),
+            Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 3][..])
+        );
+        assert_eq!(
+            SecurityError::UnknownEngineId.report_oid(),
+            Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 4][..])
+        );
+        assert_eq!(
+            SecurityError::WrongDigest.report_oid(),
+            Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 5][..])
+        );
+        assert_eq!(
+            SecurityError::DecryptionError.report_oid(),
+            Some(&[1, 3, 6, 1, 6, 3, 15, 1, 1, 6][..])
+        );
+    }
+
+    #[test]
+    fn it_returns_no_report_oid_for_malformed_security_params() {
+        assert_eq!(SecurityError::MalformedSecurityParams.report_oid(), None);
+    }
+}
@@ -0,0 +1,16 @@
+//! An implementation of the User-based Security Model (USM) for SNMPv3, as described in
+//! [RFC 3414](https://tools.ietf.org/html/rfc3414).
+
+mod auth;
+pub mod auth_key;
+pub mod engine_cache;
+mod error;
+pub mod privacy;
+pub mod report;
+mod security_params;
+
+pub use error::{SecurityError, SecurityResult};
+pub use security_params::SecurityParams;
+
+/// Placeholder used in place of the authentication parameters before they have been computed.
+pub const AUTH_PARAMS_PLACEHOLDER: [u8; 12] = [0; 12];
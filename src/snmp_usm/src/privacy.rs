@@ -0,0 +1,185 @@
+//! Privacy (encryption) ciphers used by the User-based Security Model.
+
+use crate::{SecurityError, SecurityParams, SecurityResult};
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{AsyncStreamCipher, KeyIvInit};
+use aes::Aes128;
+use cfb_mode::{Decryptor, Encryptor};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+type Aes128CfbEncryptor = Encryptor<Aes128>;
+type Aes128CfbDecryptor = Decryptor<Aes128>;
+
+/// Length, in bytes, of the AES key used for privacy (the first 16 bytes of the localized
+/// privacy key).
+const AES_KEY_LEN: usize = 16;
+
+/// Length, in bytes, of the locally generated salt carried in [priv_params](
+/// ../struct.SecurityParams.html#method.priv_params).
+const SALT_LEN: usize = 8;
+
+/// Monotonically increasing counter used to generate a unique salt for every outgoing message.
+static SALT_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// The AES-128 CFB-128 privacy cipher, as described in
+/// [RFC 3826](https://tools.ietf.org/html/rfc3826).
+///
+/// Unlike the DES-style privacy handling carried directly in
+/// [SecurityParams](../struct.SecurityParams.html), the 16-byte initialization vector is
+/// formed by concatenating the authoritative engine's `engine_boots` and `engine_time` (each
+/// encoded big-endian) with an 8-byte locally generated salt.
+///
+/// # Examples
+///
+/// ```
+/// use snmp_usm::privacy::Aes128Cfb;
+/// use snmp_usm::SecurityParams;
+///
+/// let mut security_params = SecurityParams::new();
+/// security_params.set_engine_boots(1).set_engine_time(100);
+///
+/// let localized_key = [0x11; 16];
+/// let (ciphertext, _salt) =
+///     Aes128Cfb::encrypt(&localized_key, &mut security_params, b"scoped pdu bytes");
+/// let plaintext = Aes128Cfb::decrypt(&localized_key, &security_params, &ciphertext).unwrap();
+///
+/// assert_eq!(plaintext, b"scoped pdu bytes");
+/// ```
+pub struct Aes128Cfb;
+
+impl Aes128Cfb {
+    /// Encrypts `scoped_pdu` using the first 16 bytes of `localized_key` as the AES key.
+    ///
+    /// The salt used to build the initialization vector is generated from a monotonically
+    /// increasing local counter, so it is unique for every call. It is both returned alongside
+    /// the ciphertext and stored in `security_params` via
+    /// [set_priv_params](../struct.SecurityParams.html#method.set_priv_params).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `localized_key` is shorter than 16 bytes.
+    pub fn encrypt(
+        localized_key: &[u8],
+        security_params: &mut SecurityParams,
+        scoped_pdu: &[u8],
+    ) -> (Vec<u8>, Vec<u8>) {
+        let salt = next_salt();
+        let iv = build_iv(
+            security_params.engine_boots(),
+            security_params.engine_time(),
+            &salt,
+        );
+
+        let mut ciphertext = scoped_pdu.to_vec();
+        Aes128CfbEncryptor::new(aes_key(localized_key), &iv.into()).encrypt(&mut ciphertext);
+
+        security_params.set_priv_params(&salt);
+
+        (ciphertext, salt.to_vec())
+    }
+
+    /// Decrypts `ciphertext` using the first 16 bytes of `localized_key` as the AES key.
+    ///
+    /// The initialization vector is rebuilt from `security_params`'s `engine_boots`,
+    /// `engine_time` and `priv_params` (the salt sent alongside the message).
+    ///
+    /// # Errors
+    ///
+    /// Returns [DecryptionError](../enum.SecurityError.html#variant.DecryptionError) if
+    /// `security_params.priv_params()` is not exactly 8 bytes long, which can happen for a
+    /// malformed or malicious inbound message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `localized_key` is shorter than 16 bytes.
+    pub fn decrypt(
+        localized_key: &[u8],
+        security_params: &SecurityParams,
+        ciphertext: &[u8],
+    ) -> SecurityResult<Vec<u8>> {
+        if security_params.priv_params().len() != SALT_LEN {
+            return Err(SecurityError::DecryptionError);
+        }
+
+        let iv = build_iv(
+            security_params.engine_boots(),
+            security_params.engine_time(),
+            security_params.priv_params(),
+        );
+
+        let mut plaintext = ciphertext.to_vec();
+        Aes128CfbDecryptor::new(aes_key(localized_key), &iv.into()).decrypt(&mut plaintext);
+
+        Ok(plaintext)
+    }
+}
+
+/// Returns the first 16 bytes of `localized_key` as an AES-128 key.
+///
+/// # Panics
+///
+/// Panics if `localized_key` is shorter than 16 bytes.
+fn aes_key(localized_key: &[u8]) -> &GenericArray<u8, aes::cipher::consts::U16> {
+    GenericArray::from_slice(&localized_key[..AES_KEY_LEN])
+}
+
+/// Builds the 16-byte initialization vector from `engine_boots`, `engine_time` and an 8-byte
+/// `salt`.
+///
+/// # Panics
+///
+/// Panics if `salt` is shorter than 8 bytes; callers must validate this first.
+fn build_iv(engine_boots: i32, engine_time: i32, salt: &[u8]) -> [u8; 16] {
+    let mut iv = [0u8; 16];
+    iv[0..4].copy_from_slice(&engine_boots.to_be_bytes());
+    iv[4..8].copy_from_slice(&engine_time.to_be_bytes());
+    iv[8..16].copy_from_slice(&salt[..SALT_LEN]);
+    iv
+}
+
+fn next_salt() -> [u8; SALT_LEN] {
+    let n = SALT_COUNTER.fetch_add(1, Ordering::Relaxed);
+    n.to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_a_scoped_pdu() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(1).set_engine_time(100);
+
+        let localized_key = [0x22; 16];
+        let (ciphertext, salt) = Aes128Cfb::encrypt(&localized_key, &mut security_params, b"hello world");
+
+        assert_eq!(security_params.priv_params(), salt.as_slice());
+        assert_ne!(ciphertext, b"hello world");
+
+        let plaintext = Aes128Cfb::decrypt(&localized_key, &security_params, &ciphertext).unwrap();
+        assert_eq!(plaintext, b"hello world");
+    }
+
+    #[test]
+    fn it_returns_a_decryption_error_for_a_short_salt() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_priv_params(b"short");
+
+        let localized_key = [0x44; 16];
+        let result = Aes128Cfb::decrypt(&localized_key, &security_params, b"ciphertext");
+
+        assert_eq!(result, Err(SecurityError::DecryptionError));
+    }
+
+    #[test]
+    fn it_generates_a_unique_salt_for_each_message() {
+        let mut security_params = SecurityParams::new();
+        let localized_key = [0x33; 16];
+
+        let (_, first_salt) = Aes128Cfb::encrypt(&localized_key, &mut security_params, b"a");
+        let (_, second_salt) = Aes128Cfb::encrypt(&localized_key, &mut security_params, b"a");
+
+        assert_ne!(first_salt, second_salt);
+    }
+}
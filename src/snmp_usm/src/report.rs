@@ -0,0 +1,70 @@
+//! Report PDU varbinds for USM statistics, per RFC 3414 §5.
+
+use crate::SecurityError;
+
+/// A variable binding a report generator attaches to a Report PDU in response to a security
+/// error, pairing a well-known `usmStats*` OID with its counter value.
+///
+/// # Examples
+///
+/// ```
+/// use snmp_usm::report::ReportVarBind;
+/// use snmp_usm::SecurityError;
+///
+/// let var_bind = ReportVarBind::for_error(&SecurityError::NotInTimeWindow, 1).unwrap();
+/// assert_eq!(var_bind.oid(), &[1, 3, 6, 1, 6, 3, 15, 1, 1, 2]);
+/// assert_eq!(var_bind.value(), 1);
+///
+/// assert!(ReportVarBind::for_error(&SecurityError::MalformedSecurityParams, 1).is_none());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ReportVarBind {
+    oid: Vec<u32>,
+    value: u32,
+}
+
+impl ReportVarBind {
+    /// Builds the report varbind for `error`, if it corresponds to one of the `usmStats*`
+    /// counters.
+    ///
+    /// `value` is the counter's value, as incremented by the caller, to attach alongside its OID.
+    /// Returns `None` if `error` has no associated `usmStats*` counter, see
+    /// [SecurityError::report_oid](../enum.SecurityError.html#method.report_oid).
+    pub fn for_error(error: &SecurityError, value: u32) -> Option<Self> {
+        error.report_oid().map(|oid| Self {
+            oid: oid.to_vec(),
+            value,
+        })
+    }
+
+    /// Returns the varbind's OID.
+    pub fn oid(&self) -> &[u32] {
+        &self.oid
+    }
+
+    /// Returns the varbind's counter value.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_var_bind_for_a_usm_stats_error() {
+        let var_bind = ReportVarBind::for_error(&SecurityError::UnknownUserName, 42).unwrap();
+
+        assert_eq!(var_bind.oid(), &[1, 3, 6, 1, 6, 3, 15, 1, 1, 3]);
+        assert_eq!(var_bind.value(), 42);
+    }
+
+    #[test]
+    fn it_returns_none_for_an_error_without_a_usm_stats_counter() {
+        assert_eq!(
+            ReportVarBind::for_error(&SecurityError::MalformedSecurityParams, 1),
+            None
+        );
+    }
+}
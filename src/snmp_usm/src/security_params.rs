@@ -1,4 +1,5 @@
-use crate::{SecurityError, SecurityResult, AUTH_PARAMS_PLACEHOLDER};
+use crate::auth::TIME_WINDOW;
+use crate::{SecurityError, SecurityResult};
 use yasna::{ASN1Error, ASN1ErrorKind};
 
 /// Security parameters used by the User-based Security Model.
@@ -16,12 +17,12 @@ use yasna::{ASN1Error, ASN1ErrorKind};
 /// # Examples
 ///
 /// ```
-/// use snmp_usm::SecurityParams;
+/// use snmp_usm::{SecurityParams, AUTH_PARAMS_PLACEHOLDER};
 ///
 /// let mut security_params = SecurityParams::new();
 /// security_params.set_username(b"username")
 ///     .set_priv_params(b"saltsalt")
-///     .set_auth_params_placeholder();
+///     .set_auth_params_placeholder(AUTH_PARAMS_PLACEHOLDER.len());
 /// ```
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 pub struct SecurityParams {
@@ -224,17 +225,24 @@ impl SecurityParams {
 
     /// Sets the authentication parameters placeholder.
     ///
+    /// `len` should match the truncation length of the selected authentication digest, e.g.
+    /// [Digest::TRUNCATED_LEN](../snmp_usm/auth_key/trait.Digest.html#associatedconstant.TRUNCATED_LEN)
+    /// for [Md5](../snmp_usm/auth_key/struct.Md5.html), [Sha1](../snmp_usm/auth_key/struct.Sha1.html)
+    /// and the SHA-2 digests, or [AUTH_PARAMS_PLACEHOLDER](constant.AUTH_PARAMS_PLACEHOLDER.html)`.len()`
+    /// for the original 12-byte HMAC-MD5-96/HMAC-SHA-96 placeholder.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use snmp_usm::SecurityParams;
+    /// use snmp_usm::{SecurityParams, AUTH_PARAMS_PLACEHOLDER};
     ///
     /// let mut security_params = SecurityParams::new();
-    /// security_params.set_auth_params_placeholder();
+    /// security_params.set_auth_params_placeholder(AUTH_PARAMS_PLACEHOLDER.len());
     /// assert_eq!(security_params.auth_params(), [0x0; 12]);
     /// ```
-    pub fn set_auth_params_placeholder(&mut self) -> &mut Self {
-        self.set_auth_params(&AUTH_PARAMS_PLACEHOLDER);
+    pub fn set_auth_params_placeholder(&mut self, len: usize) -> &mut Self {
+        self.auth_params.clear();
+        self.auth_params.resize(len, 0);
         self
     }
 
@@ -271,6 +279,80 @@ impl SecurityParams {
         self
     }
 
+    /// Checks that this message is within the allowed time window of the local authoritative
+    /// engine's notion of time, per RFC 3414 §3.2.
+    ///
+    /// `local_boots` and `local_time` are the receiver's own cached `engine_boots` and
+    /// `engine_time` values for the authoritative engine identified by
+    /// [engine_id](#method.engine_id).
+    ///
+    /// A message is declared outside of the time window if any of the following hold:
+    ///
+    /// * `local_boots` has reached the maximum value of `0x7FFFFFFF`, since the authoritative
+    ///   engine can never re-boot past that point without changing its engine ID.
+    /// * The message's `engine_boots` differs from `local_boots`.
+    /// * The message's `engine_time` differs from `local_time` by more than
+    ///   150 seconds (the crate's internal `TIME_WINDOW` constant).
+    ///
+    /// # Errors
+    ///
+    /// Returns [NotInTimeWindow](enum.SecurityError.html#variant.NotInTimeWindow) if the message
+    /// falls outside of the allowed window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use snmp_usm::SecurityParams;
+    ///
+    /// let mut security_params = SecurityParams::new();
+    /// security_params.set_engine_boots(1).set_engine_time(100);
+    ///
+    /// assert!(security_params.check_timeliness(1, 100).is_ok());
+    /// assert!(security_params.check_timeliness(1, 500).is_err());
+    /// ```
+    pub fn check_timeliness(&self, local_boots: i32, local_time: i32) -> SecurityResult<()> {
+        let in_time_window = local_boots != 0x7FFFFFFF
+            && self.engine_boots == local_boots
+            && (self.engine_time - local_time).abs() <= TIME_WINDOW;
+
+        if in_time_window {
+            Ok(())
+        } else {
+            Err(SecurityError::NotInTimeWindow)
+        }
+    }
+
+    /// Advances the cached `engine_boots`/`engine_time` of a non-authoritative (manager) engine
+    /// from a message received from the authoritative engine, per RFC 3414 §3.2.
+    ///
+    /// The cached values are only updated if the message is newer than what is currently cached,
+    /// i.e. `msg_boots > local_boots`, or `msg_boots == local_boots && msg_time > local_time`.
+    /// This lets later outgoing messages carry fresh timeliness values without re-discovering the
+    /// authoritative engine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use snmp_usm::SecurityParams;
+    ///
+    /// let mut security_params = SecurityParams::new();
+    /// security_params.set_engine_boots(1).set_engine_time(100);
+    /// security_params.update_from_authoritative(1, 150);
+    ///
+    /// assert_eq!(security_params.engine_time(), 150);
+    /// ```
+    pub fn update_from_authoritative(&mut self, msg_boots: i32, msg_time: i32) -> &mut Self {
+        let local_boots = self.engine_boots;
+        let local_time = self.engine_time;
+
+        if msg_boots > local_boots || (msg_boots == local_boots && msg_time > local_time) {
+            self.set_engine_boots(msg_boots);
+            self.set_engine_time(msg_time);
+        }
+
+        self
+    }
+
     /// Encodes the security parameters.
     ///
     /// A message processing subsystem can add the encoded security parameters to a message as a
@@ -310,14 +392,14 @@ impl SecurityParams {
     /// # Examples
     ///
     /// ```no_run
-    /// use snmp_usm::SecurityParams;
+    /// use snmp_usm::{SecurityParams, AUTH_PARAMS_PLACEHOLDER};
     ///
     /// # fn main() -> snmp_usm::SecurityResult<()> {
     /// # let in_security_params = [];
     /// let mut security_params =
     ///    SecurityParams::decode(&in_security_params)?;
     /// security_params.set_username(b"username")
-    ///     .set_auth_params_placeholder();
+    ///     .set_auth_params_placeholder(AUTH_PARAMS_PLACEHOLDER.len());
     /// // A message processing subsystem would set the security parameters of the outgoing message.
     /// // out_msg.set_security_params(&security_params);
     /// # Ok(())
@@ -372,4 +454,75 @@ mod tests {
 
         assert_eq!(security_params.engine_time(), 0);
     }
+
+    #[test]
+    fn it_accepts_a_timely_message() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(1).set_engine_time(100);
+
+        assert_eq!(security_params.check_timeliness(1, 200), Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_a_message_with_mismatched_engine_boots() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(2).set_engine_time(100);
+
+        assert_eq!(
+            security_params.check_timeliness(1, 100),
+            Err(SecurityError::NotInTimeWindow)
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_message_outside_the_time_window() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(1).set_engine_time(300);
+
+        assert_eq!(
+            security_params.check_timeliness(1, 100),
+            Err(SecurityError::NotInTimeWindow)
+        );
+    }
+
+    #[test]
+    fn it_rejects_when_local_boots_is_maxed_out() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(0x7FFFFFFF).set_engine_time(100);
+
+        assert_eq!(
+            security_params.check_timeliness(0x7FFFFFFF, 100),
+            Err(SecurityError::NotInTimeWindow)
+        );
+    }
+
+    #[test]
+    fn it_updates_from_a_newer_authoritative_boots() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(1).set_engine_time(100);
+        security_params.update_from_authoritative(2, 10);
+
+        assert_eq!(security_params.engine_boots(), 2);
+        assert_eq!(security_params.engine_time(), 10);
+    }
+
+    #[test]
+    fn it_updates_from_a_newer_authoritative_time() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(1).set_engine_time(100);
+        security_params.update_from_authoritative(1, 150);
+
+        assert_eq!(security_params.engine_boots(), 1);
+        assert_eq!(security_params.engine_time(), 150);
+    }
+
+    #[test]
+    fn it_ignores_a_stale_authoritative_update() {
+        let mut security_params = SecurityParams::new();
+        security_params.set_engine_boots(2).set_engine_time(100);
+        security_params.update_from_authoritative(1, 500);
+
+        assert_eq!(security_params.engine_boots(), 2);
+        assert_eq!(security_params.engine_time(), 100);
+    }
 }
@@ -0,0 +1,18 @@
+use snmp_usm::privacy::Aes128Cfb;
+use snmp_usm::SecurityParams;
+
+#[test]
+fn it_encrypts_and_decrypts_a_scoped_pdu() {
+    let mut security_params = SecurityParams::new();
+    security_params.set_engine_boots(1).set_engine_time(12345);
+
+    let localized_key = [0x44; 16];
+    let scoped_pdu = b"a scoped pdu, serialized";
+
+    let (ciphertext, salt) = Aes128Cfb::encrypt(&localized_key, &mut security_params, scoped_pdu);
+
+    assert_eq!(security_params.priv_params(), salt.as_slice());
+
+    let plaintext = Aes128Cfb::decrypt(&localized_key, &security_params, &ciphertext).unwrap();
+    assert_eq!(plaintext, scoped_pdu);
+}